@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+use std::fmt;
+
+/// Why an enum field could not be turned into a Rust enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnumTypeError {
+    /// The field name or a variant value does not produce a usable identifier:
+    /// it has no ASCII letters or digits, starts with a digit, or is a keyword.
+    InvalidName(String),
+    /// Two variant values map to the same PascalCase identifier.
+    DuplicateVariant(String),
+}
+
+impl fmt::Display for EnumTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "{name:?} is not a valid enum identifier"),
+            Self::DuplicateVariant(ident) => write!(f, "duplicate enum variant {ident}"),
+        }
+    }
+}
+
+impl std::error::Error for EnumTypeError {}
+
+/// Emits a Rust enum for an enum field. Each variant is the PascalCase form of
+/// its declared value and carries a serde rename back to that value.
+///
+/// Returns [`EnumTypeError::InvalidName`] when the field name or a value has no
+/// ASCII letters or digits, starts with a digit, or spells `Self`, and
+/// [`EnumTypeError::DuplicateVariant`] when two values share a PascalCase form.
+pub fn enum_type(field_name: &str, variants: &[&str]) -> Result<String, EnumTypeError> {
+    let mut out = String::from(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]\n",
+    );
+    out.push_str(&format!("pub enum {} {{\n", ident(field_name)?));
+    let mut seen = HashSet::new();
+    for value in variants {
+        let variant = ident(value)?;
+        if !seen.insert(variant.clone()) {
+            return Err(EnumTypeError::DuplicateVariant(variant));
+        }
+        out.push_str(&format!("    #[serde(rename = {value:?})]\n"));
+        out.push_str(&format!("    {variant},\n"));
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+/// PascalCases `name`, rejecting results that are not valid type identifiers.
+/// `Self` is the only keyword a PascalCase word can spell.
+fn ident(name: &str) -> Result<String, EnumTypeError> {
+    let ident = pascal_case(name);
+    match ident.chars().next() {
+        Some(first) if !first.is_numeric() && ident != "Self" => Ok(ident),
+        _ => Err(EnumTypeError::InvalidName(name.to_string())),
+    }
+}
+
+/// PascalCases `s`. Words are split on anything that is not an ASCII letter or
+/// digit and where a lowercase letter meets an uppercase one. Each word is
+/// capitalized and otherwise keeps its casing, except that all-caps words are
+/// lowercased so `IN_PROGRESS` and `in-progress` agree.
+fn pascal_case(s: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut prev_lower = false;
+    for c in s.chars() {
+        if !c.is_ascii_alphanumeric() {
+            prev_lower = false;
+            words.push(String::new());
+            continue;
+        }
+        if words.is_empty() || (prev_lower && c.is_ascii_uppercase()) {
+            words.push(String::new());
+        }
+        prev_lower = c.is_ascii_lowercase();
+        words.last_mut().unwrap().push(c);
+    }
+    words
+        .iter()
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let word = if word.chars().any(|c| c.is_ascii_lowercase()) {
+                word.clone()
+            } else {
+                word.to_ascii_lowercase()
+            };
+            let (first, rest) = word.split_at(1);
+            first.to_ascii_uppercase() + rest
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enum_type_for_status() {
+        assert_eq!(
+            enum_type("status", &["active", "inactive"]).unwrap(),
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]\n\
+             pub enum Status {\n    \
+             #[serde(rename = \"active\")]\n    \
+             Active,\n    \
+             #[serde(rename = \"inactive\")]\n    \
+             Inactive,\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn enum_type_pascal_cases_names() {
+        let out = enum_type("payment_state", &["in-progress", "not started", "ON_HOLD"]).unwrap();
+        assert!(out.contains("pub enum PaymentState {"));
+        assert!(out.contains("#[serde(rename = \"in-progress\")]\n    InProgress,"));
+        assert!(out.contains("#[serde(rename = \"not started\")]\n    NotStarted,"));
+        assert!(out.contains("#[serde(rename = \"ON_HOLD\")]\n    OnHold,"));
+
+        let out = enum_type("paymentState", &["inProgress", "userID", "HTTPServer"]).unwrap();
+        assert!(out.contains("pub enum PaymentState {"));
+        assert!(out.contains("#[serde(rename = \"inProgress\")]\n    InProgress,"));
+        assert!(out.contains("#[serde(rename = \"userID\")]\n    UserId,"));
+        assert!(out.contains("#[serde(rename = \"HTTPServer\")]\n    HTTPServer,"));
+    }
+
+    #[test]
+    fn enum_type_drops_non_ascii_characters() {
+        let out = enum_type("status", &["x²"]).unwrap();
+        assert!(out.contains("#[serde(rename = \"x²\")]\n    X,"));
+        assert_eq!(
+            enum_type("status", &["²"]),
+            Err(EnumTypeError::InvalidName("²".to_string()))
+        );
+    }
+
+    #[test]
+    fn enum_type_rejects_keyword() {
+        assert_eq!(
+            enum_type("status", &["self"]),
+            Err(EnumTypeError::InvalidName("self".to_string()))
+        );
+    }
+
+    #[test]
+    fn enum_type_rejects_leading_digit() {
+        assert_eq!(
+            enum_type("rank", &["1st"]),
+            Err(EnumTypeError::InvalidName("1st".to_string()))
+        );
+        assert_eq!(
+            enum_type("2fa", &["on"]),
+            Err(EnumTypeError::InvalidName("2fa".to_string()))
+        );
+    }
+
+    #[test]
+    fn enum_type_rejects_empty_identifier() {
+        assert_eq!(
+            enum_type("status", &[""]),
+            Err(EnumTypeError::InvalidName(String::new()))
+        );
+        assert_eq!(
+            enum_type("status", &["--"]),
+            Err(EnumTypeError::InvalidName("--".to_string()))
+        );
+    }
+
+    #[test]
+    fn enum_type_rejects_colliding_variants() {
+        assert_eq!(
+            enum_type("status", &["in-progress", "IN_PROGRESS"]),
+            Err(EnumTypeError::DuplicateVariant("InProgress".to_string()))
+        );
+    }
+}
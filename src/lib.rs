@@ -1,3 +1,6 @@
+pub mod codegen;
+pub mod struct_tag;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
@@ -0,0 +1,389 @@
+use std::fmt;
+
+/// A parsed Go-style struct tag such as `json:"user_name,omitempty" xml:"user"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StructTag {
+    entries: Vec<Entry>,
+}
+
+/// One `key:"name,option,..."` pair of a struct tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    key: String,
+    name: String,
+    options: Vec<String>,
+}
+
+/// Parses a struct tag. Like Go's `reflect.StructTag`, a pair whose value
+/// cannot be decoded is skipped, and parsing stops at the first structurally
+/// malformed pair (a bad key, a missing `:"` or no closing quote).
+pub fn parse(tag: &str) -> StructTag {
+    let entries = scan(tag).0.iter().filter_map(Pair::entry).collect();
+    StructTag { entries }
+}
+
+/// One structurally valid `key:"..."` pair, with its value still quoted.
+struct Pair<'a> {
+    key: &'a str,
+    /// The whole pair as written, from the key to the closing quote.
+    raw: &'a str,
+    quoted: &'a str,
+}
+
+impl Pair<'_> {
+    /// Decodes the pair into an entry, or `None` if its value does not unquote.
+    fn entry(&self) -> Option<Entry> {
+        let value = unquote(self.quoted)?;
+        let mut parts = value.split(',');
+        let name = parts.next().unwrap_or_default().to_string();
+        Some(Entry {
+            key: self.key.to_string(),
+            name,
+            options: parts.map(str::to_string).collect(),
+        })
+    }
+}
+
+/// Splits `tag` into pairs the way Go's `reflect.StructTag.Lookup` does: the
+/// key runs up to `:`, and the value runs to the next unescaped `"`. Returns
+/// the pairs and the remainder starting at the first malformed pair.
+fn scan(tag: &str) -> (Vec<Pair<'_>>, &str) {
+    let mut pairs = Vec::new();
+    let mut rest = tag;
+    loop {
+        rest = rest.trim_start_matches(' ');
+        let key_len = rest
+            .find(|c: char| c <= ' ' || c == ':' || c == '"' || c == '\x7f')
+            .unwrap_or(rest.len());
+        if key_len == 0 || !rest[key_len..].starts_with(":\"") {
+            break;
+        }
+        let mut end = None;
+        let mut chars = rest[key_len + 2..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    end = Some(key_len + 2 + i + 1);
+                    break;
+                }
+                '\\' => {
+                    chars.next();
+                }
+                _ => {}
+            }
+        }
+        let Some(end) = end else {
+            break;
+        };
+        pairs.push(Pair {
+            key: &rest[..key_len],
+            raw: &rest[..end],
+            quoted: &rest[key_len + 1..end],
+        });
+        rest = &rest[end..];
+    }
+    (pairs, rest)
+}
+
+/// Merges two struct tags key by key. Every `base` pair whose key appears in
+/// `overrides` is replaced by all of that key's pairs from `overrides`, in their
+/// original order; keys only in `base` are kept, so an override tag that omits
+/// `json` still inherits it. Pairs are copied as written, including ones whose
+/// value does not decode, and anything after a malformed pair in either tag is
+/// carried over verbatim rather than dropped.
+pub fn merge_struct_tags(base: &str, overrides: &str) -> String {
+    let (overrides, overrides_tail) = scan(overrides);
+    let (base, base_tail) = scan(base);
+    let overridden = |key: &str| overrides.iter().any(|p| p.key == key);
+    let mut replaced = Vec::new();
+    let mut parts = Vec::new();
+    for pair in &base {
+        if !overridden(pair.key) {
+            parts.push(pair.raw);
+        } else if !replaced.contains(&pair.key) {
+            replaced.push(pair.key);
+            let replacements = overrides.iter().filter(|p| p.key == pair.key);
+            parts.extend(replacements.map(|p| p.raw));
+        }
+    }
+    let added = overrides.iter().filter(|p| !replaced.contains(&p.key));
+    parts.extend(added.map(|p| p.raw));
+    for tail in [base_tail, overrides_tail] {
+        let tail = tail.trim_end_matches(' ');
+        if !tail.is_empty() {
+            parts.push(tail);
+        }
+    }
+    parts.join(" ")
+}
+
+/// Decodes a complete double-quoted Go string literal. Returns `None` for
+/// escapes Go rejects, for `\x` and octal bytes outside ASCII (which a `String`
+/// cannot hold), and for raw newlines.
+fn unquote(s: &str) -> Option<String> {
+    let mut chars = s.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        let decoded = match c {
+            '\\' => match chars.next()? {
+                'a' => '\x07',
+                'b' => '\x08',
+                'f' => '\x0c',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'v' => '\x0b',
+                '\\' => '\\',
+                '"' => '"',
+                'x' => numeric_escape(&mut chars, 16, 2).filter(char::is_ascii)?,
+                'u' => numeric_escape(&mut chars, 16, 4)?,
+                'U' => numeric_escape(&mut chars, 16, 8)?,
+                d @ '0'..='7' => {
+                    let rest = numeric_escape(&mut chars, 8, 2)? as u32;
+                    char::from_u32(d.to_digit(8)? * 64 + rest).filter(char::is_ascii)?
+                }
+                _ => return None,
+            },
+            '"' | '\n' => return None,
+            c => c,
+        };
+        value.push(decoded);
+    }
+    Some(value)
+}
+
+fn numeric_escape(chars: &mut std::str::Chars<'_>, radix: u32, digits: usize) -> Option<char> {
+    let mut code = 0;
+    for _ in 0..digits {
+        code = code * radix + chars.next()?.to_digit(radix)?;
+    }
+    char::from_u32(code)
+}
+
+/// Writes `value` as a double-quoted Go string literal that `unquote` reads back.
+fn quote(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in value.chars() {
+        match c {
+            '\x07' => f.write_str("\\a")?,
+            '\x08' => f.write_str("\\b")?,
+            '\x0c' => f.write_str("\\f")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            '\x0b' => f.write_str("\\v")?,
+            '\\' => f.write_str("\\\\")?,
+            '"' => f.write_str("\\\"")?,
+            c if c.is_ascii_control() => write!(f, "\\x{:02x}", c as u32)?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    f.write_str("\"")
+}
+
+impl StructTag {
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.key == key)
+    }
+
+    /// The serialized name from the `json` key, if it renames the field. A bare
+    /// `-` skips the field instead, but `-,` renames it to `-`.
+    pub fn rename(&self) -> Option<&str> {
+        self.get("json")
+            .filter(|e| !e.name.is_empty() && (e.name != "-" || !e.options.is_empty()))
+            .map(|e| e.name.as_str())
+    }
+
+    /// Whether the `json` key carries the `omitempty` option.
+    pub fn omit_empty(&self) -> bool {
+        self.get("json").is_some_and(|e| e.has_option("omitempty"))
+    }
+
+    /// Whether the `json` key is `-`, i.e. the field is never serialized.
+    pub fn skip(&self) -> bool {
+        self.get("json")
+            .is_some_and(|e| e.name == "-" && e.options.is_empty())
+    }
+}
+
+impl Entry {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    pub fn has_option(&self, option: &str) -> bool {
+        self.options.iter().any(|o| o == option)
+    }
+}
+
+impl fmt::Display for StructTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.name.clone();
+        for option in &self.options {
+            value.push(',');
+            value.push_str(option);
+        }
+        write!(f, "{}:", self.key)?;
+        quote(f, &value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_json_rename_and_omitempty() {
+        let tag = parse(r#"json:"user_name,omitempty""#);
+        assert_eq!(tag.entries().len(), 1);
+        assert_eq!(tag.rename(), Some("user_name"));
+        assert!(tag.omit_empty());
+        assert!(!tag.skip());
+        assert_eq!(tag.to_string(), r#"json:"user_name,omitempty""#);
+    }
+
+    #[test]
+    fn parse_multiple_keys() {
+        let tag = parse(r#"json:"-" xml:"user,attr""#);
+        assert!(tag.skip());
+        assert_eq!(tag.rename(), None);
+        let xml = tag.get("xml").unwrap();
+        assert_eq!(xml.name(), "user");
+        assert!(xml.has_option("attr"));
+    }
+
+    #[test]
+    fn json_dash_with_options_renames_to_dash() {
+        let tag = parse(r#"json:"-,""#);
+        assert!(!tag.skip());
+        assert_eq!(tag.rename(), Some("-"));
+    }
+
+    #[test]
+    fn parse_decodes_escapes() {
+        let tag = parse(r#"json:"a\nb\t\"q\"\\\x41\u00e9""#);
+        assert_eq!(tag.get("json").unwrap().name(), "a\nb\t\"q\"\\A\u{e9}");
+    }
+
+    #[test]
+    fn parse_decodes_octal_escapes() {
+        let tag = parse(r#"json:"a" xml:"\101" yaml:"y""#);
+        assert_eq!(tag.get("xml").unwrap().name(), "A");
+        assert_eq!(tag.get("yaml").unwrap().name(), "y");
+    }
+
+    #[test]
+    fn parse_skips_undecodable_pairs() {
+        for bad in [
+            r#"xml:"\xff""#,
+            r#"xml:"\q""#,
+            "xml:\"a\nb\"",
+            r#"xml:"\777""#,
+        ] {
+            let tag = parse(&format!(r#"json:"a" {bad} yaml:"y""#));
+            let keys: Vec<_> = tag.entries().iter().map(Entry::key).collect();
+            assert_eq!(keys, ["json", "yaml"], "{bad}");
+        }
+    }
+
+    #[test]
+    fn display_round_trips_escaped_values() {
+        for tag in [
+            r#"json:"a\nb,omitempty""#,
+            r#"json:"\"quoted\"" xml:"back\\slash""#,
+            r#"json:"\x01\t\u0085""#,
+        ] {
+            assert_eq!(parse(tag).to_string(), tag);
+        }
+    }
+
+    #[test]
+    fn merge_keeps_base_keys_missing_from_override() {
+        assert_eq!(
+            merge_struct_tags(r#"json:"edges""#, r#"xml:"Edges""#),
+            r#"json:"edges" xml:"Edges""#
+        );
+        assert_eq!(
+            merge_struct_tags(r#"json:"edges" xml:"e""#, r#"xml:"Edges""#),
+            r#"json:"edges" xml:"Edges""#
+        );
+    }
+
+    #[test]
+    fn merge_replaces_every_copy_of_a_key() {
+        assert_eq!(
+            merge_struct_tags(r#"json:"a" xml:"x" json:"b""#, r#"json:"c""#),
+            r#"json:"c" xml:"x""#
+        );
+    }
+
+    #[test]
+    fn merge_keeps_every_copy_of_a_repeated_override_key() {
+        assert_eq!(
+            merge_struct_tags(r#"json:"j""#, r#"xml:"a" xml:"b""#),
+            r#"json:"j" xml:"a" xml:"b""#
+        );
+        assert_eq!(
+            merge_struct_tags(r#"json:"j" xml:"z""#, r#"xml:"a" xml:"b""#),
+            r#"json:"j" xml:"a" xml:"b""#
+        );
+    }
+
+    #[test]
+    fn merge_keeps_undecodable_pairs() {
+        assert_eq!(
+            merge_struct_tags(r#"json:"a" xml:"\xff""#, r#"yaml:"y""#),
+            r#"json:"a" xml:"\xff" yaml:"y""#
+        );
+        assert_eq!(
+            merge_struct_tags(r#"json:"a" xml:"\xff""#, r#"xml:"x""#),
+            r#"json:"a" xml:"x""#
+        );
+    }
+
+    #[test]
+    fn merge_keeps_unparsed_tail() {
+        assert_eq!(
+            merge_struct_tags(r#"json:"a" bad xml:"x""#, r#"yaml:"y""#),
+            r#"json:"a" yaml:"y" bad xml:"x""#
+        );
+        assert_eq!(
+            merge_struct_tags(r#"json:"a""#, r#"xml:"x" oops"#),
+            r#"json:"a" xml:"x" oops"#
+        );
+    }
+
+    #[test]
+    fn parse_stops_at_malformed_pair() {
+        let tag = parse(r#"json:"name" broken xml:"x""#);
+        assert_eq!(tag.entries().len(), 1);
+        assert_eq!(tag.rename(), Some("name"));
+        assert_eq!(parse(r#"json:"a" xml:"unterminated"#).entries().len(), 1);
+        assert_eq!(parse(r#"json:"a" xml: "x""#).entries().len(), 1);
+    }
+}